@@ -5,10 +5,14 @@
 //! the data is already in memory in a rust collection, or if it needs
 //! to be iterated, for example because it is read from disk.
 
-use arrow;
+use std::sync::Arc;
+
 use arrow::array::{Int32Array, Int32Builder,
                    Float64Array,
-                   StringArray, StringBuilder};
+                   StringArray, StringBuilder,
+                   ListArray,
+                   Array, ArrayRef};
+use arrow::datatypes::{DataType, Int32Type};
 
 
 /// Create an integer array with the `from` constructor.
@@ -109,6 +113,8 @@ pub fn create_array_with_collect() -> Int32Array {
 pub fn create_string_array_with_builder() -> StringArray {
     let mut builder = StringBuilder::with_capacity(4, 32);
     builder.append_value("foo");
+    // append_value takes anything that implements `AsRef<str>`, not just `&str`.
+    #[allow(clippy::unnecessary_to_owned)]
     builder.append_value("bar".to_string());
     builder.append_null();
     builder.append_option(Some("foobar"));
@@ -123,3 +129,65 @@ pub fn create_string_array_with_builder() -> StringArray {
 pub fn create_string_array_with_collect() -> StringArray {
     vec!["foo", "bar", "foobar"].into_iter().map(Some).collect::<StringArray>()
 }
+
+/// Create a nested array of integer lists.
+///
+/// So far every array in this chapter has held a flat list of scalars.
+/// Arrow also supports nested types, where each element of the array is
+/// itself a sequence of values. `ListArray::from_iter_primitive` builds
+/// one from an iterator of `Option<Vec<Option<T>>>`: the outer `Option`
+/// marks whether the list itself is missing, and the inner `Option`s mark
+/// missing values inside a present list.
+///
+/// This example creates a list array equivalent to
+/// `[Some([1, None, 3]), None, Some([])]`, mixing a list with a null
+/// value, a missing list, and an empty (but present) list.
+pub fn create_nested_list_array() -> ListArray {
+    ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+        Some(vec![Some(1), None, Some(3)]),
+        None,
+        Some(vec![]),
+    ])
+}
+
+/// Create a heterogeneous vector of type-erased arrays.
+///
+/// Every recipe so far works with a single, known array type. Real
+/// pipelines often process a schema of columns whose types are only
+/// known at runtime, so they store arrays as `ArrayRef`, an alias for
+/// `Arc<dyn Array>` that erases the concrete type behind a trait object.
+pub fn create_array_ref_vec() -> Vec<ArrayRef> {
+    vec![
+        Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        Arc::new(Float64Array::from(vec![1., 1.5, 2.])) as ArrayRef,
+        Arc::new(StringArray::from(vec!["foo", "bar", "foobar"])) as ArrayRef,
+    ]
+}
+
+/// Recover the concrete type of each array in a `Vec<ArrayRef>`.
+///
+/// To do anything type-specific with an `ArrayRef`, we need to downcast
+/// it back to its concrete type. `data_type()` tells us which downcast to
+/// attempt, and `as_any().downcast_ref` performs it, returning `None` if
+/// we guessed wrong. Real code typically dispatches on every `DataType`
+/// it expects to handle and treats an unexpected one as an error; here we
+/// just print a message for it instead.
+pub fn downcast_array_ref_vec(arrays: &[ArrayRef]) {
+    for array in arrays {
+        match array.data_type() {
+            DataType::Int32 => {
+                let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+                println!("Int32Array: {:?}", array);
+            }
+            DataType::Float64 => {
+                let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+                println!("Float64Array: {:?}", array);
+            }
+            DataType::Utf8 => {
+                let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+                println!("StringArray: {:?}", array);
+            }
+            data_type => println!("no recipe to downcast data type: {:?}", data_type),
+        }
+    }
+}
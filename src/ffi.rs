@@ -0,0 +1,76 @@
+//! Arrow Cookbook: C Data Interface
+//!
+//! The C Data Interface lets Arrow implementations in different languages
+//! and processes share arrays without copying the underlying memory. This
+//! is how the Rust implementation talks to `pyarrow`, `duckdb`, `polars`,
+//! and any other Arrow consumer that speaks the same protocol.
+//!
+//! Exporting an array produces two C structs, `FFI_ArrowSchema` (the data
+//! type, encoded as a `format` string, plus flags such as nullability) and
+//! `FFI_ArrowArray` (the length, null count, and raw buffer pointers).
+//! Importing reverses the process, rebuilding an `ArrayData` from those
+//! same two structs. Ownership is the part that is easy to get wrong: the
+//! exported struct must keep its `Buffer`s alive for as long as the
+//! consumer holds it, and the consumer must call the `release` callback
+//! embedded in the struct once it is done, or the memory is leaked.
+
+use arrow::array::{Array, StringArray, UInt8Array, make_array};
+use arrow::ffi::{from_ffi, to_ffi};
+
+use crate::low_level_api;
+
+/// Export a `UInt8Array` over the C Data Interface, then import it back.
+///
+/// `to_ffi` derives `FFI_ArrowSchema` from the array's `DataType` and wraps
+/// the array's buffers (here, a data buffer and a null buffer, since
+/// `create_int_array_from_buffer` now builds `[Some(1), None, Some(3)]`)
+/// in an `FFI_ArrowArray`. The buffers themselves are not copied, only
+/// their pointers are shared, so the `FFI_ArrowArray` keeps the original
+/// `Buffer`s alive internally until its `release` callback runs.
+///
+/// `from_ffi` is the importer's half: it reads the two structs back into
+/// an `ArrayData`, which `make_array` turns into a concrete array again.
+/// It is `unsafe` because it trusts the caller to have received structs
+/// that are still valid, i.e. not yet released.
+pub fn roundtrip_int_array_ffi() -> UInt8Array {
+    let array = low_level_api::create_int_array_from_buffer();
+    let array_data = array.into_data();
+
+    let (ffi_array, ffi_schema) = to_ffi(&array_data).unwrap();
+    println!("exported format string: {:?}", ffi_schema.format());
+    println!("exported null count: {:?}", array_data.null_count());
+
+    let imported_data = unsafe { from_ffi(ffi_array, &ffi_schema) }.unwrap();
+    imported_data.validate_full().unwrap();
+
+    make_array(imported_data)
+        .as_any()
+        .downcast_ref::<UInt8Array>()
+        .unwrap()
+        .clone()
+}
+
+/// Round-trip a `StringArray` over the C Data Interface.
+///
+/// A `StringArray` has three buffers (validity, offsets and values), all
+/// of which travel as a single `FFI_ArrowArray` with three buffer pointers.
+/// This shows that the interface generalizes to multi-buffer layouts
+/// without any special casing: the importer does not need to know how
+/// many buffers a type uses ahead of time, it just reads whatever
+/// `FFI_ArrowArray` reports.
+pub fn roundtrip_string_array_ffi() -> StringArray {
+    let array = StringArray::from(vec!["this", "is", "an", "array"]);
+    let array_data = array.into_data();
+
+    let (ffi_array, ffi_schema) = to_ffi(&array_data).unwrap();
+    println!("exported format string: {:?}", ffi_schema.format());
+
+    let imported_data = unsafe { from_ffi(ffi_array, &ffi_schema) }.unwrap();
+    imported_data.validate_full().unwrap();
+
+    make_array(imported_data)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .clone()
+}
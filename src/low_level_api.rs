@@ -8,11 +8,13 @@
 //! better understand the Arrow internal representation.
 
 use arrow::array::{UInt8Array,UInt8Builder,
-                   StringArray,
+                   StringArray,StringViewArray,
                    ArrayData,Array};
 use arrow::buffer::Buffer;
 use arrow::datatypes::DataType;
 
+use crate::create_arrays;
+
 /// Introspect a simple integer array.
 ///
 /// An Arrow array, in its simplest case is a location in the memory, with
@@ -25,7 +27,7 @@ use arrow::datatypes::DataType;
 pub fn introspect_int_array() {
     let array = UInt8Array::from(vec![1, 2, 3]);
 
-    let array_data = array.data();
+    let array_data = array.to_data();
     println!("array len: {:?}", array_data.len());
     println!("array data_type: {:?}", array_data.data_type());
 
@@ -52,7 +54,7 @@ pub fn introspect_int_array_capacity() {
     let array = builder.finish();
     println!("introspect int array capacity: {:?}", array);
 
-    let array_data = array.data();
+    let array_data = array.to_data();
     println!("array len: {:?}", array_data.len());
 
     let buffer = &array_data.buffers()[0];
@@ -92,7 +94,7 @@ pub fn introspect_int_array_with_nulls() {
     println!("introspect int array with nulls: {:?}", array);
 
     // Basic array information
-    let array_data = array.data();
+    let array_data = array.to_data();
     println!("array len: {:?}", array_data.len());
     println!("array data_type: {:?}", array_data.data_type());
 
@@ -103,14 +105,13 @@ pub fn introspect_int_array_with_nulls() {
     println!("data buffer data: {:?}", data_buffer.as_slice());
 
     // Null buffer information
-    match array_data.null_buffer() {
-        Some(null_buffer) => {
+    match array_data.nulls() {
+        Some(nulls) => {
+            let null_buffer = nulls.buffer();
             println!("null buffer pointer: {:?}", null_buffer.as_ptr());
             println!("null buffer capacity: {:?}", null_buffer.capacity());
             println!("null buffer bits: {:08b}", null_buffer.as_slice()[0]);
-
-            let null_bitmap = array_data.null_bitmap().unwrap();
-            println!("null bitmap bit_len: {:?}", null_bitmap.bit_len());
+            println!("null bitmap bit_len: {:?}", nulls.len());
         }
         None => println!("null buffer does not exist")
     }
@@ -122,7 +123,7 @@ pub fn introspect_string_array() {
     println!("introspect string array: {:?}", array);
 
     // Basic array information
-    let array_data = array.data();
+    let array_data = array.to_data();
     println!("array len: {:?}", array_data.len());
     println!("array data_type: {:?}", array_data.data_type());
 
@@ -142,15 +143,112 @@ pub fn introspect_string_array() {
 
 }
 
+/// Introspect a string array using the view-based layout.
+///
+/// `StringViewArray` is a newer representation of the same logical data as
+/// `StringArray`, but laid out very differently. Instead of an offsets
+/// buffer plus a single data buffer, it has one fixed-width buffer of
+/// 16-byte "views", plus zero or more variable-length data buffers.
+///
+/// Each view encodes its string in one of two ways, depending on length:
+///
+/// * If the string is 12 bytes or shorter, it is stored inline inside the
+///   view itself, so reading it needs no indirection at all.
+/// * Otherwise, the view stores a 4-byte length, a 4-byte prefix of the
+///   string (useful to short-circuit comparisons), a buffer index, and an
+///   offset into that buffer where the full string lives.
+///
+/// This is what makes substring and append operations on `StringViewArray`
+/// cheaper than on `StringArray`: short strings never touch a data buffer,
+/// and appending to a long string's buffer never needs to shift any
+/// existing offsets, unlike the single growing data buffer of `StringArray`.
+pub fn introspect_string_view_array() {
+    let array = StringViewArray::from(vec!["this", "is", "an", "array"]);
+    println!("introspect string view array: {:?}", array);
+
+    let array_data = array.to_data();
+    println!("array len: {:?}", array_data.len());
+    println!("array data_type: {:?}", array_data.data_type());
+
+    // The views buffer: one 16-byte view per element.
+    let views_buffer = &array_data.buffers()[0];
+    println!("views buffer len: {:?}", views_buffer.len());
+    println!("views buffer data: {:?}", views_buffer.as_slice());
+
+    // The variable-length data buffers, holding any string longer than
+    // 12 bytes. With only short strings in this example, this is empty.
+    for (i, data_buffer) in array_data.buffers()[1..].iter().enumerate() {
+        println!("data buffer {:?} len: {:?}", i, data_buffer.len());
+        println!("data buffer {:?} data: {:?}", i, data_buffer.as_slice());
+    }
+}
+
+/// Introspect a nested list array.
+///
+/// A list array is represented with an i32 offsets buffer (one more
+/// element than the array's length, the same layout as `StringArray`'s
+/// offsets), the list's own null bitmap, and a single child `ArrayData`
+/// holding every value of every list flattened into one array, along with
+/// its own validity buffer.
+///
+/// This complements `create_int_array_from_buffer`, which passes an empty
+/// `child_data` vector because it has no nested values: here, `child_data`
+/// is where the nesting actually lives.
+pub fn introspect_list_array() {
+    let array = create_arrays::create_nested_list_array();
+    println!("introspect list array: {:?}", array);
+
+    let array_data = array.to_data();
+    println!("array len: {:?}", array_data.len());
+    println!("array data_type: {:?}", array_data.data_type());
+
+    // Offsets buffer: one i32 per element, plus a final one.
+    let offsets_buffer = &array_data.buffers()[0];
+    println!("offsets buffer len: {:?}", offsets_buffer.len());
+    println!("offsets buffer data: {:?}", offsets_buffer.typed_data::<i32>());
+
+    // Null bitmap for the list array itself (e.g. the missing list).
+    match array_data.nulls() {
+        Some(nulls) => println!("list null buffer bits: {:08b}", nulls.buffer().as_slice()[0]),
+        None => println!("list null buffer does not exist"),
+    }
+
+    // The child holds every value of every list, flattened.
+    let child_data = &array_data.child_data()[0];
+    println!("child values len: {:?}", child_data.len());
+    println!("child values data: {:?}", child_data.buffers()[0].typed_data::<i32>());
+    match child_data.nulls() {
+        Some(nulls) => println!("child null buffer bits: {:08b}", nulls.buffer().as_slice()[0]),
+        None => println!("child null buffer does not exist"),
+    }
+}
+
+/// Create an integer array with nulls directly from raw buffers.
+///
+/// This builds the equivalent of `[Some(1), None, Some(3)]` by hand,
+/// using `ArrayData::try_new` the same way `create_int_array_from_buffer`
+/// did before, but now also passing a null buffer instead of `None`.
+///
+/// The null buffer is a packed bitmap: bit `i` is set when element `i` is
+/// valid. Bits are LSB-first within each byte, so for our three elements
+/// `[1, None, 3]`, positions 0 and 2 are valid and position 1 is null,
+/// which packs to the single byte `0b00000101`. The bitmap must be at
+/// least `ceil(len / 8)` bytes long, one byte here since `len` is 3.
+///
+/// `ArrayData::validate` checks these invariants (buffer lengths, bitmap
+/// size, etc.) explicitly, which is useful when assembling `ArrayData` by
+/// hand instead of going through a builder or constructor that guarantees
+/// them for us.
 pub fn create_int_array_from_buffer() -> UInt8Array {
-    let data = Buffer::from([1, 2, 3]);
-    // let nulls = Buffer::from([0b00000010]);
+    let data = Buffer::from([1, 0, 3]);
+    let nulls = Buffer::from([0b00000101]);
     let array_data = ArrayData::try_new(DataType::UInt8,
                                         3, // length of the array
-                                        None, // bit mask with nulls
+                                        Some(nulls), // bit mask with nulls
                                         0, // offset
                                         vec![data], // vector of buffers
                                         vec![]).unwrap(); // child_data
-                                        
+    array_data.validate().unwrap();
+
     UInt8Array::from(array_data)
 }
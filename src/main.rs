@@ -1,5 +1,7 @@
 mod create_arrays;
+mod ffi;
 mod low_level_api;
+mod mutate_arrays;
 
 fn call_create_arrays() {
     println!("Int32Array::from(vec![1, 2, 3]): {:?}",
@@ -18,6 +20,9 @@ fn call_create_arrays() {
              create_arrays::create_string_array_with_builder());
     println!("vec![\"foo\", \"bar\", \"foobar\"].into_iter().map(Some).collect::<StringArray>(): {:?}",
              create_arrays::create_string_array_with_collect());
+    println!("ListArray::from_iter_primitive::<Int32Type, _, _>(...): {:?}",
+             create_arrays::create_nested_list_array());
+    create_arrays::downcast_array_ref_vec(&create_arrays::create_array_ref_vec());
 }
 
 fn call_low_level_api() {
@@ -25,11 +30,29 @@ fn call_low_level_api() {
     low_level_api::introspect_int_array_capacity();
     low_level_api::introspect_int_array_with_nulls();
     low_level_api::introspect_string_array();
+    low_level_api::introspect_string_view_array();
+    low_level_api::introspect_list_array();
     println!("ArrayData::try_new(...): {:?}",
              low_level_api::create_int_array_from_buffer());
 }
 
+fn call_ffi() {
+    println!("roundtrip UInt8Array through the C Data Interface: {:?}",
+             ffi::roundtrip_int_array_ffi());
+    println!("roundtrip StringArray through the C Data Interface: {:?}",
+             ffi::roundtrip_string_array_ffi());
+}
+
+fn call_mutate_arrays() {
+    println!("unary_mut on an owned Int32Array: {:?}",
+             mutate_arrays::mutate_owned_array_in_place());
+    println!("unary_mut on a shared Int32Array falling back to a copy: {:?}",
+             mutate_arrays::mutate_shared_array_with_copy());
+}
+
 fn main() {
     call_create_arrays();
     call_low_level_api();
+    call_ffi();
+    call_mutate_arrays();
 }
@@ -0,0 +1,57 @@
+//! Arrow Cookbook: Mutating arrays in place
+//!
+//! Arrow arrays are immutable by convention, but their underlying buffers
+//! are plain reference-counted allocations. If an array is the sole owner
+//! of its buffer (the buffer's reference count is one, and the array has
+//! no offset into it), a unary function can be applied element-wise by
+//! overwriting that allocation instead of allocating a new one. This is
+//! the copy-on-write idiom that `unary_mut` implements, and it is what
+//! lets compute kernels avoid an allocation on every step of a pipeline.
+//!
+//! If the buffer is shared (for example because the array was cloned),
+//! mutating in place would silently corrupt the other owner's view of the
+//! data, so `unary_mut` falls back to building a fresh array instead.
+
+use arrow::array::{Array, Int32Array};
+use arrow::compute::unary_mut;
+
+/// Double every element of an `Int32Array` that uniquely owns its buffer.
+///
+/// Since `array` was just created and never cloned, its buffer's
+/// reference count is one, so `unary_mut` mutates it in place. We print
+/// the buffer pointer before and after the call to prove no new
+/// allocation happened: both pointers are the same.
+pub fn mutate_owned_array_in_place() -> Int32Array {
+    let array = Int32Array::from(vec![1, 2, 3]);
+    let ptr_before = array.to_data().buffers()[0].as_ptr();
+
+    let array = unary_mut(array, |x| x * 2).unwrap();
+    let ptr_after = array.to_data().buffers()[0].as_ptr();
+
+    println!("owned array buffer pointer before: {:?}", ptr_before);
+    println!("owned array buffer pointer after: {:?}", ptr_after);
+    array
+}
+
+/// Double every element of an `Int32Array` whose buffer is shared.
+///
+/// Here `array` is cloned before the call, so its buffer's reference
+/// count is two and `unary_mut` cannot mutate it safely: it returns the
+/// original array back as `Err`, and we fall back to `unary`, which
+/// allocates a new buffer for the result. The printed pointers differ,
+/// showing the new allocation.
+pub fn mutate_shared_array_with_copy() -> Int32Array {
+    let array = Int32Array::from(vec![1, 2, 3]);
+    let _clone = array.clone();
+    let ptr_before = array.to_data().buffers()[0].as_ptr();
+
+    let array = match unary_mut(array, |x| x * 2) {
+        Ok(array) => array,
+        Err(array) => arrow::compute::unary(&array, |x| x * 2),
+    };
+    let ptr_after = array.to_data().buffers()[0].as_ptr();
+
+    println!("shared array buffer pointer before: {:?}", ptr_before);
+    println!("shared array buffer pointer after: {:?}", ptr_after);
+    array
+}